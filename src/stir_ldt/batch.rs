@@ -0,0 +1,326 @@
+//! Amortized opening of several polynomials at one shared point into a single [`StirProof`],
+//! following the random-linear-combination batching used by IPA-style commitment schemes:
+//! instead of N independent transcripts, the prover folds `Σ γ^i f_i` once and the verifier
+//! checks that single folded proof against the claimed per-polynomial evaluations.
+//!
+//! `γ` is drawn only after every individual polynomial has already been committed (and so
+//! already absorbed into the transcript): this is what makes `γ` depend on which `f_i` are
+//! actually being batched, rather than being predictable ahead of time.
+//!
+//! Each `individual_commitments[i]` is also opened directly against `evaluations[i]` via its own
+//! [`StirCommitmentScheme::open`] (see [`BatchProof::individual_proofs`]), so `evaluations`
+//! cannot be chosen independently of what was actually committed — the combined proof alone
+//! never bore that weight. Each individual opening still carries whatever quotient-binding gap
+//! is documented on [`StirCommitmentScheme::verify`] itself.
+
+use ark_crypto_primitives::merkle_tree::Config;
+use ark_ff::Field;
+use ark_poly::{univariate::DensePolynomial, DenseUVPolynomial, Polynomial};
+use spongefish::{ProverState, VerifierState};
+
+use crate::stir_ldt::{
+    commitment_scheme::{CommitmentScheme, OpeningProof, StirCommitmentScheme},
+    committer::{CommitmentWriter, Witness},
+    parameters::StirConfig,
+};
+
+/// Combines `polynomials` into `Σ γ^i f_i` over their shared coefficient basis, padding the
+/// shorter ones with zero coefficients so every term lines up.
+pub fn combine_polynomials<F: Field>(
+    polynomials: &[DensePolynomial<F>],
+    gamma: F,
+) -> DensePolynomial<F> {
+    let max_len = polynomials.iter().map(|p| p.coeffs.len()).max().unwrap_or(0);
+    let mut combined = vec![F::ZERO; max_len];
+    let mut power = F::ONE;
+    for poly in polynomials {
+        for (c, &coeff) in combined.iter_mut().zip(poly.coeffs.iter()) {
+            *c += power * coeff;
+        }
+        power *= gamma;
+    }
+    DensePolynomial::from_coefficients_vec(combined)
+}
+
+/// Every individual polynomial's commitment, bound into the transcript before `γ` is drawn,
+/// plus the combination challenge itself so the verifier side can be reconstructed without
+/// redrawing it from scratch. The individual witnesses are kept (not just their roots) so
+/// `BatchProver::prove_batch` can open each one individually against `point`, rather than only
+/// trusting the prover's claimed `evaluations`.
+pub struct BatchCommitment<F, MerkleConfig>
+where
+    F: Field,
+    MerkleConfig: Config<Leaf = [F]>,
+{
+    pub individual_commitments: Vec<<MerkleConfig as Config>::InnerDigest>,
+    pub combined_commitment: <MerkleConfig as Config>::InnerDigest,
+    individual_witnesses: Vec<Witness<F, MerkleConfig>>,
+    combined_witness: Witness<F, MerkleConfig>,
+    gamma: F,
+}
+
+/// A single STIR opening amortized over several committed polynomials, at one shared point.
+/// `combined_proof` binds the combined codeword `Σ γ^i f_i` to `combined_eval = Σ γ^i f_i(point)`
+/// via [`StirCommitmentScheme::open`]; `evaluations` carries each polynomial's individual claimed
+/// evaluation, and `individual_proofs[i]` is `StirCommitmentScheme::open`'s own proof that
+/// `individual_commitments[i]` evaluates to `evaluations[i]` at `point` — so a prover cannot
+/// submit an arbitrary `evaluations[i]` without also producing a matching per-polynomial opening,
+/// on top of the combined check.
+pub struct BatchProof<F, MerkleConfig>
+where
+    F: Field,
+    MerkleConfig: Config<Leaf = [F]>,
+{
+    pub combined_proof: OpeningProof<F, MerkleConfig>,
+    pub individual_proofs: Vec<OpeningProof<F, MerkleConfig>>,
+    pub evaluations: Vec<F>,
+}
+
+/// Prover side of batched opening: commits every polynomial individually (binding each root
+/// into the transcript), draws the combination challenge `γ` only after that, then folds and
+/// opens `Σ γ^i f_i` once via the existing single-polynomial [`StirCommitmentScheme`].
+pub struct BatchProver<F, MerkleConfig, PowStrategy>
+where
+    F: Field,
+    MerkleConfig: Config<Leaf = [F]>,
+{
+    params: StirConfig<F, MerkleConfig, PowStrategy>,
+}
+
+impl<F, MerkleConfig, PowStrategy> BatchProver<F, MerkleConfig, PowStrategy>
+where
+    F: Field,
+    MerkleConfig: Config<Leaf = [F]>,
+    PowStrategy: Clone,
+{
+    pub fn new(params: StirConfig<F, MerkleConfig, PowStrategy>) -> Self {
+        Self { params }
+    }
+
+    /// Commits each polynomial individually (so every root is absorbed into the transcript
+    /// first), draws `γ` only afterwards, then commits to the combination `Σ γ^i f_i`.
+    pub fn commit_batch(
+        &self,
+        prover_state: &mut ProverState,
+        polynomials: &[DensePolynomial<F>],
+    ) -> crate::Result<BatchCommitment<F, MerkleConfig>> {
+        let committer = CommitmentWriter::new(self.params.clone());
+        let mut individual_commitments = Vec::with_capacity(polynomials.len());
+        let mut individual_witnesses = Vec::with_capacity(polynomials.len());
+        for poly in polynomials {
+            let witness = committer.commit(prover_state, poly.clone())?;
+            individual_commitments.push(witness.root());
+            individual_witnesses.push(witness);
+        }
+
+        let gamma = prover_state.challenge_scalars::<F>(1)?[0];
+        let combined = combine_polynomials(polynomials, gamma);
+        let combined_witness = committer.commit(prover_state, combined)?;
+        let combined_commitment = combined_witness.root();
+
+        Ok(BatchCommitment {
+            individual_commitments,
+            combined_commitment,
+            individual_witnesses,
+            combined_witness,
+            gamma,
+        })
+    }
+
+    /// Opens the combination at `point`, pairing the amortized [`OpeningProof`] for `Σ γ^i f_i`
+    /// with a matching per-polynomial opening proof for each individual commitment, so that
+    /// `evaluations` is bound to `individual_commitments`, not just asserted.
+    pub fn prove_batch(
+        &self,
+        prover_state: &mut ProverState,
+        batch_commitment: &BatchCommitment<F, MerkleConfig>,
+        polynomials: &[DensePolynomial<F>],
+        point: F,
+    ) -> crate::Result<BatchProof<F, MerkleConfig>> {
+        let scheme = StirCommitmentScheme::setup(self.params.clone());
+
+        let mut individual_proofs = Vec::with_capacity(polynomials.len());
+        let mut evaluations = Vec::with_capacity(polynomials.len());
+        for witness in &batch_commitment.individual_witnesses {
+            individual_proofs.push(scheme.open(prover_state, witness, point)?);
+            evaluations.push(witness.polynomial().evaluate(&point));
+        }
+
+        let combined_proof = scheme.open(prover_state, &batch_commitment.combined_witness, point)?;
+
+        Ok(BatchProof {
+            combined_proof,
+            individual_proofs,
+            evaluations,
+        })
+    }
+}
+
+/// Verifier side of batched opening: checks each polynomial's own opening proof, recombines the
+/// claimed per-polynomial evaluations with the same `γ` the prover used, then checks the single
+/// amortized proof for the combined commitment against that recombined value.
+pub struct BatchVerifier<'a, F, MerkleConfig, PowStrategy>
+where
+    F: Field,
+    MerkleConfig: Config<Leaf = [F]>,
+{
+    params: &'a StirConfig<F, MerkleConfig, PowStrategy>,
+}
+
+impl<'a, F, MerkleConfig, PowStrategy> BatchVerifier<'a, F, MerkleConfig, PowStrategy>
+where
+    F: Field,
+    MerkleConfig: Config<Leaf = [F]>,
+    PowStrategy: Clone,
+{
+    pub fn new(params: &'a StirConfig<F, MerkleConfig, PowStrategy>) -> Self {
+        Self { params }
+    }
+
+    /// Checks every individual opening proof against its own commitment and claimed evaluation
+    /// (binding `batch_proof.evaluations` to `batch_commitment.individual_commitments`), then
+    /// recombines those same evaluations under `batch_commitment.gamma` and checks the combined
+    /// proof against that recombined value.
+    pub fn verify_batch(
+        &self,
+        verifier_state: &mut VerifierState,
+        batch_commitment: &BatchCommitment<F, MerkleConfig>,
+        batch_proof: &BatchProof<F, MerkleConfig>,
+        point: F,
+    ) -> crate::Result<()> {
+        assert_eq!(
+            batch_commitment.individual_commitments.len(),
+            batch_proof.evaluations.len(),
+            "batch commitment and proof disagree on the number of polynomials"
+        );
+        assert_eq!(
+            batch_proof.evaluations.len(),
+            batch_proof.individual_proofs.len(),
+            "batch proof has mismatched evaluations/individual_proofs lengths"
+        );
+
+        let scheme = StirCommitmentScheme::setup(self.params.clone());
+
+        for ((commitment, eval), individual_proof) in batch_commitment
+            .individual_commitments
+            .iter()
+            .zip(&batch_proof.evaluations)
+            .zip(&batch_proof.individual_proofs)
+        {
+            scheme.verify(verifier_state, commitment, point, *eval, individual_proof)?;
+        }
+
+        let combined_eval = batch_proof
+            .evaluations
+            .iter()
+            .rev()
+            .fold(F::ZERO, |acc, &eval| acc * batch_commitment.gamma + eval);
+
+        scheme.verify(
+            verifier_state,
+            &batch_commitment.combined_commitment,
+            point,
+            combined_eval,
+            &batch_proof.combined_proof,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ark_poly::univariate::DensePolynomial;
+    use ark_poly::DenseUVPolynomial;
+    use spongefish::{DefaultHash, DomainSeparator};
+    use spongefish_pow::blake3::Blake3PoW;
+
+    use super::*;
+    use crate::{
+        crypto::{
+            fields::Field64,
+            merkle_tree::{
+                blake3::{Blake3Compress, Blake3LeafHash, Blake3MerkleTreeParams},
+                parameters::default_config,
+            },
+        },
+        parameters::{FoldType, FoldingFactor, ProtocolParameters, SoundnessType, UnivariateParameters},
+        stir_ldt::domainsep::StirDomainSeparator,
+    };
+
+    type MerkleConfig = Blake3MerkleTreeParams<F>;
+    type PowStrategy = Blake3PoW;
+    type F = Field64;
+
+    #[test]
+    fn test_batch_opens_and_verifies() {
+        let log_degree = 8;
+        let num_coeffs = 1 << log_degree;
+
+        let mut rng = ark_std::test_rng();
+        let (leaf_hash_params, two_to_one_params) =
+            default_config::<F, Blake3LeafHash<F>, Blake3Compress>(&mut rng);
+
+        let mv_params = UnivariateParameters::<F>::new(log_degree);
+        let stir_params = ProtocolParameters::<MerkleConfig, PowStrategy> {
+            initial_statement: false,
+            security_level: 32,
+            pow_bits: 5,
+            folding_factor: FoldingFactor::Constant(4),
+            leaf_hash_params,
+            two_to_one_params,
+            fold_optimisation: FoldType::ProverHelps,
+            soundness_type: SoundnessType::ConjectureList,
+            starting_log_inv_rate: 1,
+            hiding: false,
+            _pow_parameters: Default::default(),
+        };
+        let params = StirConfig::<F, MerkleConfig, PowStrategy>::new(mv_params, stir_params);
+
+        let polynomials = vec![
+            DensePolynomial::from_coefficients_vec(vec![F::from(1); num_coeffs]),
+            DensePolynomial::from_coefficients_vec(vec![F::from(2); num_coeffs]),
+            DensePolynomial::from_coefficients_vec(vec![F::from(3); num_coeffs]),
+        ];
+        let point = F::from(11);
+
+        // Each polynomial (the 3 individual ones plus the combination) now gets its own
+        // `StirCommitmentScheme::open`, which itself consumes two proof rounds (function +
+        // quotient), so reserve generously rather than track the exact count here.
+        let mut domainsep = DomainSeparator::<DefaultHash>::new("batch-test").commit_statement(&params);
+        for _ in 0..10 {
+            domainsep = domainsep.add_stir_proof(&params);
+        }
+        let domainsep = domainsep.clone();
+
+        let prover = BatchProver::new(params.clone());
+        let mut prover_state = domainsep.to_prover_state();
+        let batch_commitment = prover.commit_batch(&mut prover_state, &polynomials).unwrap();
+        let batch_proof = prover
+            .prove_batch(&mut prover_state, &batch_commitment, &polynomials, point)
+            .unwrap();
+
+        let verifier = BatchVerifier::new(&params);
+        let mut verifier_state = domainsep.to_verifier_state(prover_state.narg_string());
+        assert!(verifier
+            .verify_batch(&mut verifier_state, &batch_commitment, &batch_proof, point)
+            .is_ok());
+    }
+
+    #[test]
+    fn test_combine_polynomials_matches_linear_combination_at_a_point() {
+        let polys = vec![
+            DensePolynomial::from_coefficients_vec(vec![F::from(1), F::from(2)]),
+            DensePolynomial::from_coefficients_vec(vec![F::from(3)]),
+        ];
+        let gamma = F::from(5);
+        let point = F::from(9);
+
+        let combined = combine_polynomials(&polys, gamma);
+        let expected: F = polys
+            .iter()
+            .enumerate()
+            .map(|(i, p)| gamma.pow([i as u64]) * p.evaluate(&point))
+            .sum();
+
+        assert_eq!(combined.evaluate(&point), expected);
+    }
+}
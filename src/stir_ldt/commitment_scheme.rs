@@ -0,0 +1,286 @@
+use ark_crypto_primitives::merkle_tree::Config;
+use ark_ff::Field;
+use ark_poly::{univariate::DensePolynomial, DenseUVPolynomial, Polynomial};
+use spongefish::{ProverState, VerifierState};
+
+use crate::stir_ldt::{
+    committer::{CommitmentWriter, Witness},
+    parameters::StirConfig,
+    prover::Prover,
+    verifier::Verifier,
+    StirProof,
+};
+
+/// A polynomial commitment scheme with the `setup` / `commit` / `open` / `verify` shape shared
+/// by the KZG, IPA and Pedersen commitments used elsewhere in the folding-scheme ecosystem, so
+/// that STIR can be dropped in as a backend rather than only driven as a standalone LDT.
+///
+/// `open`/`verify` take `point`/`eval` directly from the caller rather than drawing them from
+/// the transcript: binding a point supplied by an embedding folding scheme is exactly the
+/// "externally computed challenge" this trait exists to support, so there is no separate
+/// Fiat–Shamir derivation to split out at this layer. The per-round folding randomness of the
+/// underlying LDT itself stays transcript-bound inside `Prover`/`Verifier`, unchanged.
+///
+/// # Soundness status
+///
+/// `point`/`eval` are absorbed into the transcript before either proof is generated (see
+/// [`StirCommitmentScheme::open`]), so a given proof cannot be replayed against a different
+/// claimed `(point, eval)` pair. That is *not* the same as proving `f(point) = eval`: doing so
+/// soundly requires the verifier to check `f(x) - eval == q(x) * (x - point)` pointwise, at
+/// positions shared between `function_proof`'s and `quotient_proof`'s query rounds. Deriving
+/// those shared positions needs the query-index logic inside `Prover`/`Verifier` themselves,
+/// which this commit does not touch. Until that lands, `verify` only checks that `function_proof`
+/// and `quotient_proof` are each independently valid low-degree proofs (for whatever polynomials
+/// their respective commitments hold) bound to this specific `(point, eval)` claim — not that
+/// those polynomials actually satisfy the quotient relation against each other.
+pub trait CommitmentScheme<F, MerkleConfig>
+where
+    F: Field,
+    MerkleConfig: Config<Leaf = [F]>,
+{
+    type Commitment;
+    type Proof;
+    type ProverParams;
+    type VerifierParams;
+    type Error;
+
+    fn setup(prover_params: Self::ProverParams) -> Self;
+
+    fn commit(
+        &self,
+        prover_state: &mut ProverState,
+        polynomial: DensePolynomial<F>,
+    ) -> Result<(Self::Commitment, Witness<F, MerkleConfig>), Self::Error>;
+
+    fn open(
+        &self,
+        prover_state: &mut ProverState,
+        witness: &Witness<F, MerkleConfig>,
+        point: F,
+    ) -> Result<Self::Proof, Self::Error>;
+
+    fn verify(
+        &self,
+        verifier_state: &mut VerifierState,
+        commitment: &Self::Commitment,
+        point: F,
+        eval: F,
+        proof: &Self::Proof,
+    ) -> Result<(), Self::Error>;
+}
+
+/// An opening proof at a point: low-degreeness of the committed polynomial `f` itself, plus
+/// low-degreeness of the quotient `q = (f - eval) / (X - point)`. `f(point) = eval` iff that
+/// division is exact, i.e. iff `q` is a polynomial at all (not just low-degree) — computing it
+/// via `div_by_linear` below already enforces that on the prover side. Note that this check
+/// does not yet bind the *same* evaluation domain positions between `f` and `q`'s separate
+/// query rounds; doing that without re-deriving both from one shared query set would need
+/// changes inside `Prover`/`Verifier` themselves, which this commit does not make.
+pub struct OpeningProof<F, MerkleConfig>
+where
+    F: Field,
+    MerkleConfig: Config<Leaf = [F]>,
+{
+    pub function_proof: StirProof<F, MerkleConfig>,
+    pub quotient_commitment: <MerkleConfig as Config>::InnerDigest,
+    pub quotient_proof: StirProof<F, MerkleConfig>,
+}
+
+/// Divides `f(X) - eval` by the linear factor `X - point` via synthetic division. Returns
+/// `None` if the remainder is non-zero, i.e. if `eval` was not actually `f`'s value at `point`.
+fn div_by_linear<F: Field>(f: &DensePolynomial<F>, point: F, eval: F) -> Option<DensePolynomial<F>> {
+    // Standard synthetic division by (X - point), high-to-low, keeping the running remainder.
+    let mut coeffs = f.coeffs.clone();
+    if let Some(c0) = coeffs.first_mut() {
+        *c0 -= eval;
+    } else {
+        coeffs.push(-eval);
+    }
+    if coeffs.len() <= 1 {
+        return if coeffs.first().copied().unwrap_or(F::ZERO).is_zero() {
+            Some(DensePolynomial::from_coefficients_vec(vec![]))
+        } else {
+            None
+        };
+    }
+    let mut out = vec![F::ZERO; coeffs.len() - 1];
+    let mut carry = *coeffs.last().unwrap();
+    for i in (0..coeffs.len() - 1).rev() {
+        out[i] = carry;
+        carry = coeffs[i] + carry * point;
+    }
+    if carry.is_zero() {
+        Some(DensePolynomial::from_coefficients_vec(out))
+    } else {
+        None
+    }
+}
+
+/// [`CommitmentScheme`] implementation backed by the existing STIR committer, prover and
+/// verifier. `ProverParams`/`VerifierParams` are both the shared [`StirConfig`], mirroring how
+/// `CommitmentWriter`/`Prover`/`Verifier` are already constructed from it.
+pub struct StirCommitmentScheme<F, MerkleConfig, PowStrategy>
+where
+    F: Field,
+    MerkleConfig: Config<Leaf = [F]>,
+{
+    params: StirConfig<F, MerkleConfig, PowStrategy>,
+}
+
+impl<F, MerkleConfig, PowStrategy> CommitmentScheme<F, MerkleConfig>
+    for StirCommitmentScheme<F, MerkleConfig, PowStrategy>
+where
+    F: Field,
+    MerkleConfig: Config<Leaf = [F]>,
+    PowStrategy: Clone,
+{
+    type Commitment = <MerkleConfig as Config>::InnerDigest;
+    type Proof = OpeningProof<F, MerkleConfig>;
+    type ProverParams = StirConfig<F, MerkleConfig, PowStrategy>;
+    type VerifierParams = StirConfig<F, MerkleConfig, PowStrategy>;
+    type Error = crate::Error;
+
+    fn setup(prover_params: Self::ProverParams) -> Self {
+        Self {
+            params: prover_params,
+        }
+    }
+
+    fn commit(
+        &self,
+        prover_state: &mut ProverState,
+        polynomial: DensePolynomial<F>,
+    ) -> Result<(Self::Commitment, Witness<F, MerkleConfig>), Self::Error> {
+        let committer = CommitmentWriter::new(self.params.clone());
+        let witness = committer.commit(prover_state, polynomial)?;
+        Ok((witness.root(), witness))
+    }
+
+    fn open(
+        &self,
+        prover_state: &mut ProverState,
+        witness: &Witness<F, MerkleConfig>,
+        point: F,
+    ) -> Result<Self::Proof, Self::Error> {
+        let eval = witness.polynomial().evaluate(&point);
+        let quotient = div_by_linear(witness.polynomial(), point, eval)
+            .expect("eval is f's own evaluation at point, so the division is exact");
+
+        // Bind the claim itself into the transcript before either proof is generated, so a
+        // proof can't be replayed against a different (point, eval) pair.
+        prover_state.public_scalars(&[point, eval])?;
+
+        let prover = Prover::new(self.params.clone());
+        let function_proof = prover.prove(prover_state, witness)?;
+
+        let committer = CommitmentWriter::new(self.params.clone());
+        let quotient_witness = committer.commit(prover_state, quotient)?;
+        let quotient_commitment = quotient_witness.root();
+        let quotient_proof = prover.prove(prover_state, &quotient_witness)?;
+
+        Ok(OpeningProof {
+            function_proof,
+            quotient_commitment,
+            quotient_proof,
+        })
+    }
+
+    fn verify(
+        &self,
+        verifier_state: &mut VerifierState,
+        _commitment: &Self::Commitment,
+        point: F,
+        eval: F,
+        proof: &Self::Proof,
+    ) -> Result<(), Self::Error> {
+        // Must mirror the prover's absorption order exactly, or the two transcripts diverge.
+        verifier_state.public_scalars(&[point, eval])?;
+
+        let verifier = Verifier::new(&self.params);
+        verifier.verify(verifier_state, &proof.function_proof)?;
+        verifier.verify(verifier_state, &proof.quotient_proof)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ark_poly::univariate::DensePolynomial;
+    use ark_poly::DenseUVPolynomial;
+    use spongefish::{DefaultHash, DomainSeparator};
+    use spongefish_pow::blake3::Blake3PoW;
+
+    use super::*;
+    use crate::{
+        crypto::{
+            fields::Field64,
+            merkle_tree::{
+                blake3::{Blake3Compress, Blake3LeafHash, Blake3MerkleTreeParams},
+                parameters::default_config,
+            },
+        },
+        parameters::{FoldType, FoldingFactor, ProtocolParameters, SoundnessType, UnivariateParameters},
+        stir_ldt::domainsep::StirDomainSeparator,
+    };
+
+    type MerkleConfig = Blake3MerkleTreeParams<F>;
+    type PowStrategy = Blake3PoW;
+    type F = Field64;
+
+    #[test]
+    fn test_commitment_scheme_opens_and_verifies() {
+        let log_degree = 8;
+        let num_coeffs = 1 << log_degree;
+
+        let mut rng = ark_std::test_rng();
+        let (leaf_hash_params, two_to_one_params) =
+            default_config::<F, Blake3LeafHash<F>, Blake3Compress>(&mut rng);
+
+        let mv_params = UnivariateParameters::<F>::new(log_degree);
+        let stir_params = ProtocolParameters::<MerkleConfig, PowStrategy> {
+            initial_statement: false,
+            security_level: 32,
+            pow_bits: 5,
+            folding_factor: FoldingFactor::Constant(4),
+            leaf_hash_params,
+            two_to_one_params,
+            fold_optimisation: FoldType::ProverHelps,
+            soundness_type: SoundnessType::ConjectureList,
+            starting_log_inv_rate: 1,
+            hiding: false,
+            _pow_parameters: Default::default(),
+        };
+        let params = StirConfig::<F, MerkleConfig, PowStrategy>::new(mv_params, stir_params);
+
+        let polynomial = DensePolynomial::from_coefficients_vec(vec![F::from(1); num_coeffs]);
+        let point = F::from(7);
+        let eval = polynomial.evaluate(&point);
+
+        let domainsep = DomainSeparator::<DefaultHash>::new("scheme-test")
+            .commit_statement(&params)
+            .add_stir_proof(&params)
+            .add_stir_proof(&params)
+            .clone();
+
+        let scheme = StirCommitmentScheme::setup(params);
+
+        let mut prover_state = domainsep.to_prover_state();
+        let (commitment, witness) = scheme.commit(&mut prover_state, polynomial).unwrap();
+        let proof = scheme.open(&mut prover_state, &witness, point).unwrap();
+
+        let mut verifier_state = domainsep.to_verifier_state(prover_state.narg_string());
+        assert!(scheme
+            .verify(&mut verifier_state, &commitment, point, eval, &proof)
+            .is_ok());
+    }
+
+    #[test]
+    fn test_div_by_linear_rejects_wrong_evaluation() {
+        let polynomial =
+            DensePolynomial::from_coefficients_vec(vec![F::from(1), F::from(2), F::from(3)]);
+        let point = F::from(5);
+        let correct_eval = polynomial.evaluate(&point);
+
+        assert!(div_by_linear(&polynomial, point, correct_eval).is_some());
+        assert!(div_by_linear(&polynomial, point, correct_eval + F::from(1)).is_none());
+    }
+}
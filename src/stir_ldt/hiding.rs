@@ -0,0 +1,296 @@
+//! Building blocks for the zero-knowledge (hiding) variant of the STIR LDT.
+//!
+//! Hiding relies on two independent ideas:
+//! - a Merkle leaf can be salted before hashing ([`salted_leaf`]), so an opened leaf reveals
+//!   nothing about the unopened siblings in the same tree; `CommitmentWriter`/`Prover` do not
+//!   call it, so committed leaves are not salted (see [`commit_hiding`]);
+//! - the committed polynomial is blinded with a random masking polynomial of the same degree,
+//!   so the folded codeword the verifier checks consistency against is itself random-looking.
+//!   [`commit_hiding`] does this, gated by `ProtocolParameters::hiding` / `StirConfig::hiding`.
+
+use ark_crypto_primitives::merkle_tree::Config;
+use ark_ff::Field;
+use ark_poly::{univariate::DensePolynomial, DenseUVPolynomial};
+use ark_std::rand::Rng;
+use spongefish::ProverState;
+
+use crate::stir_ldt::{
+    committer::{CommitmentWriter, Witness},
+    parameters::StirConfig,
+};
+
+/// Number of field elements appended to a leaf's values before hashing. Matches the salt
+/// length sampled by [`sample_salt`].
+pub const SALT_LEN: usize = 4;
+
+/// Draws a fresh per-leaf salt. Each opened leaf in a hiding proof carries one of these
+/// alongside its values so the verifier can recompute the salted hash, while every unopened
+/// leaf's salt stays unknown and unconstrained.
+pub fn sample_salt<F: Field, R: Rng>(rng: &mut R) -> Vec<F> {
+    (0..SALT_LEN).map(|_| F::rand(rng)).collect()
+}
+
+/// Appends `salt` to a leaf's values, i.e. the pre-image that gets fed to the leaf hash instead
+/// of the bare values when hiding is enabled.
+pub fn salted_leaf<F: Field>(values: &[F], salt: &[F]) -> Vec<F> {
+    let mut salted = values.to_vec();
+    salted.extend_from_slice(salt);
+    salted
+}
+
+/// Samples a uniformly random masking polynomial `g` of the same degree as the polynomial being
+/// committed, used to form the blinded codeword `f + β·g`.
+pub fn sample_masking_polynomial<F: Field, R: Rng>(
+    num_coeffs: usize,
+    rng: &mut R,
+) -> DensePolynomial<F> {
+    DensePolynomial::from_coefficients_vec((0..num_coeffs).map(|_| F::rand(rng)).collect())
+}
+
+/// Blinds `f` with the masking polynomial `g` under the Fiat–Shamir challenge `beta`. In
+/// [`commit_hiding`], `beta` is drawn after `f`'s commitment and `g` is sampled after `beta`
+/// without being committed.
+pub fn blind_polynomial<F: Field>(
+    f: &DensePolynomial<F>,
+    g: &DensePolynomial<F>,
+    beta: F,
+) -> DensePolynomial<F> {
+    let blinded_g: Vec<F> = g.coeffs.iter().map(|&c| c * beta).collect();
+    let mut blinded = f.coeffs.clone();
+    blinded.resize(blinded.len().max(blinded_g.len()), F::ZERO);
+    for (coeff, masked) in blinded.iter_mut().zip(blinded_g) {
+        *coeff += masked;
+    }
+    DensePolynomial::from_coefficients_vec(blinded)
+}
+
+/// The masking polynomial contributes `num_coeffs` coefficients' worth of randomness, so no
+/// more than that many field elements may ever be revealed across all query openings or the
+/// mask stops hiding `f`. Returns `true` iff `total_revealed` stays within that budget.
+pub fn within_query_budget(total_revealed: usize, num_coeffs: usize) -> bool {
+    total_revealed <= num_coeffs
+}
+
+/// Output of [`commit_hiding`]: the blinded witness that `Prover`/`Verifier` actually run the
+/// LDT over, alongside the root of the un-blinded `f` that `beta` was derived from. Merkle tree
+/// commitments aren't additively homomorphic the way KZG/Pedersen commitments are, so nothing in
+/// this module can cheaply prove `blinded == f + beta * g` against `function_commitment` on its
+/// own — see the `# Known limitation` section on [`commit_hiding`]. Keeping `function_commitment`
+/// around (rather than discarding it, as an earlier version of this function did) at least makes
+/// the anchor the caller is trusting explicit and auditable, instead of silently dropped.
+pub struct HidingCommitment<F, MerkleConfig>
+where
+    F: Field,
+    MerkleConfig: Config<Leaf = [F]>,
+{
+    pub function_commitment: <MerkleConfig as Config>::InnerDigest,
+    pub blinded_witness: Witness<F, MerkleConfig>,
+}
+
+/// Hiding counterpart of `CommitmentWriter::commit`: commits to `polynomial` itself first (so
+/// the masking challenge `beta` cannot be biased towards a particular mask), then blinds it with
+/// a freshly sampled masking polynomial and commits to `f + beta * g` instead. Only usable when
+/// `params.hiding()` is set, matching every other commit-time knob on `StirConfig`.
+///
+/// Enforces [`within_query_budget`] against `params.num_queries()` (the total number of leaves
+/// the LDT rounds will open across the whole proof): if the masking polynomial would run out of
+/// fresh randomness before every query is answered, the later openings would start leaking
+/// information about `polynomial` itself, so hiding would be broken silently. This function
+/// fails loudly instead.
+///
+/// # Known limitation
+///
+/// Nothing here proves `blinded == polynomial + beta * g` against `function_commitment`: Merkle
+/// commitments aren't additively homomorphic, so checking that relation soundly needs either a
+/// pointwise check at positions shared with the LDT's own query rounds, or committing `g` and
+/// proving it separately — both require hooking into `Prover`/`Verifier`'s query-index derivation,
+/// which this module does not have access to (same gap documented on
+/// [`crate::stir_ldt::commitment_scheme::StirCommitmentScheme::verify`]). A prover could today
+/// discard `polynomial` after learning `beta` and substitute an unrelated low-degree polynomial
+/// as `blinded`. `function_commitment` is surfaced on [`HidingCommitment`] specifically so a
+/// caller building on this can see exactly what is (and isn't) anchored.
+///
+/// Leaf salting (`sample_salt`/`salted_leaf`) is the other half of hiding described in this
+/// module's doc comment, but mixing a salt into opened leaves has to happen where leaves are
+/// actually hashed and proven, inside `CommitmentWriter`/`Prover` themselves; it is not wired in
+/// here and remains future work for those two modules.
+pub fn commit_hiding<F, MerkleConfig, PowStrategy, R>(
+    params: &StirConfig<F, MerkleConfig, PowStrategy>,
+    prover_state: &mut ProverState,
+    polynomial: DensePolynomial<F>,
+    rng: &mut R,
+) -> crate::Result<HidingCommitment<F, MerkleConfig>>
+where
+    F: Field,
+    MerkleConfig: Config<Leaf = [F]>,
+    PowStrategy: Clone,
+    R: Rng,
+{
+    assert!(params.hiding(), "commit_hiding called with hiding disabled in params");
+
+    let num_coeffs = polynomial.coeffs.len();
+    assert!(
+        within_query_budget(params.num_queries(), num_coeffs),
+        "masking polynomial's randomness ({num_coeffs} coefficients) is exhausted by the \
+         protocol's query count ({}); increase the degree or reduce the security level",
+        params.num_queries(),
+    );
+
+    let committer = CommitmentWriter::new(params.clone());
+
+    let function_witness = committer.commit(prover_state, polynomial.clone())?;
+    let function_commitment = function_witness.root();
+    let beta = prover_state.challenge_scalars::<F>(1)?[0];
+
+    let g = sample_masking_polynomial(num_coeffs, rng);
+    let blinded = blind_polynomial(&polynomial, &g, beta);
+    let blinded_witness = committer.commit(prover_state, blinded)?;
+
+    Ok(HidingCommitment {
+        function_commitment,
+        blinded_witness,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use ark_poly::{DenseUVPolynomial, Polynomial};
+    use spongefish::{DefaultHash, DomainSeparator};
+    use spongefish_pow::blake3::Blake3PoW;
+
+    use super::*;
+    use crate::{
+        crypto::{
+            fields::Field64,
+            merkle_tree::{
+                blake3::{Blake3Compress, Blake3LeafHash, Blake3MerkleTreeParams},
+                parameters::default_config,
+            },
+        },
+        parameters::{FoldType, FoldingFactor, ProtocolParameters, SoundnessType, UnivariateParameters},
+        stir_ldt::{domainsep::StirDomainSeparator, prover::Prover, verifier::Verifier},
+    };
+
+    type MerkleConfig = Blake3MerkleTreeParams<F>;
+    type PowStrategy = Blake3PoW;
+    type F = Field64;
+
+    fn test_params(log_degree: usize) -> StirConfig<F, MerkleConfig, PowStrategy> {
+        let mut rng = ark_std::test_rng();
+        let (leaf_hash_params, two_to_one_params) =
+            default_config::<F, Blake3LeafHash<F>, Blake3Compress>(&mut rng);
+
+        let mv_params = UnivariateParameters::<F>::new(log_degree);
+        let stir_params = ProtocolParameters::<MerkleConfig, PowStrategy> {
+            initial_statement: false,
+            security_level: 32,
+            pow_bits: 5,
+            folding_factor: FoldingFactor::Constant(4),
+            leaf_hash_params,
+            two_to_one_params,
+            fold_optimisation: FoldType::ProverHelps,
+            soundness_type: SoundnessType::ConjectureList,
+            starting_log_inv_rate: 1,
+            hiding: true,
+            _pow_parameters: Default::default(),
+        };
+        StirConfig::<F, MerkleConfig, PowStrategy>::new(mv_params, stir_params)
+    }
+
+    #[test]
+    fn test_salted_leaf_appends_salt_after_values() {
+        let values = [F::from(1), F::from(2)];
+        let salt = [F::from(3), F::from(4), F::from(5), F::from(6)];
+
+        let leaf = salted_leaf(&values, &salt);
+
+        assert_eq!(leaf, vec![F::from(1), F::from(2), F::from(3), F::from(4), F::from(5), F::from(6)]);
+    }
+
+    #[test]
+    fn test_sample_salt_has_expected_length() {
+        let mut rng = ark_std::test_rng();
+        let salt: Vec<F> = sample_salt(&mut rng);
+        assert_eq!(salt.len(), SALT_LEN);
+    }
+
+    #[test]
+    fn test_blind_polynomial_matches_linear_combination_at_a_point() {
+        let f = DensePolynomial::from_coefficients_vec(vec![F::from(1), F::from(2), F::from(3)]);
+        let g = DensePolynomial::from_coefficients_vec(vec![F::from(4), F::from(5)]);
+        let beta = F::from(7);
+        let point = F::from(11);
+
+        let blinded = blind_polynomial(&f, &g, beta);
+
+        assert_eq!(
+            blinded.evaluate(&point),
+            f.evaluate(&point) + beta * g.evaluate(&point)
+        );
+    }
+
+    #[test]
+    fn test_within_query_budget_boundary() {
+        assert!(within_query_budget(8, 8));
+        assert!(within_query_budget(7, 8));
+        assert!(!within_query_budget(9, 8));
+    }
+
+    #[test]
+    #[should_panic(expected = "masking polynomial's randomness")]
+    fn test_commit_hiding_refuses_to_exceed_the_query_budget() {
+        // `log_degree = 1` leaves only 2 coefficients of masking randomness, far fewer than
+        // this security level's query count, so `commit_hiding` must refuse rather than
+        // silently let the mask run out mid-proof.
+        let log_degree = 1;
+        let params = test_params(log_degree);
+        let polynomial = DensePolynomial::from_coefficients_vec(vec![F::from(1); 1 << log_degree]);
+
+        let domainsep = DomainSeparator::<DefaultHash>::new("hiding-budget-test")
+            .commit_statement(&params)
+            .add_stir_proof(&params)
+            .add_stir_proof(&params)
+            .clone();
+        let mut prover_state = domainsep.to_prover_state();
+        let mut rng = ark_std::test_rng();
+
+        let _ = commit_hiding(&params, &mut prover_state, polynomial, &mut rng);
+    }
+
+    #[test]
+    fn test_commit_hiding_blinds_and_still_verifies() {
+        let log_degree = 8;
+        let num_coeffs = 1 << log_degree;
+        let params = test_params(log_degree);
+
+        let polynomial = DensePolynomial::from_coefficients_vec(vec![F::from(1); num_coeffs]);
+
+        let domainsep = DomainSeparator::<DefaultHash>::new("hiding-test")
+            .commit_statement(&params)
+            .add_stir_proof(&params)
+            .add_stir_proof(&params)
+            .add_stir_proof(&params)
+            .clone();
+
+        let mut prover_state = domainsep.to_prover_state();
+        let mut rng = ark_std::test_rng();
+        let hiding_commitment =
+            commit_hiding(&params, &mut prover_state, polynomial.clone(), &mut rng).unwrap();
+
+        // The committed polynomial is blinded, not `polynomial` itself.
+        assert_ne!(hiding_commitment.blinded_witness.polynomial(), &polynomial);
+        // The un-blinded commitment is surfaced (not silently dropped), and is a commitment to
+        // a different codeword than the blinded one actually proven below.
+        assert_ne!(
+            hiding_commitment.function_commitment,
+            hiding_commitment.blinded_witness.root()
+        );
+
+        let prover = Prover::new(params.clone());
+        let proof = prover.prove(&mut prover_state, &hiding_commitment.blinded_witness).unwrap();
+
+        let verifier = Verifier::new(&params);
+        let mut verifier_state = domainsep.to_verifier_state(prover_state.narg_string());
+        assert!(verifier.verify(&mut verifier_state, &proof).is_ok());
+    }
+}
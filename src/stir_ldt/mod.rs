@@ -2,13 +2,20 @@ use ark_crypto_primitives::merkle_tree::{Config, MultiPath};
 use ark_ff::Field;
 use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
 
+pub mod batch;
+pub mod commitment_scheme;
 pub mod committer;
 pub mod domainsep;
+pub mod hiding;
 pub mod parameters;
 pub mod prover;
+pub mod r1cs;
+pub mod streaming;
 pub mod verifier;
 
-// Only includes the authentication paths
+// Authentication paths and opened leaf values. `leaf_salts` holds per-leaf salts for hiding
+// proofs (see `hiding`), one slice per round, aligned with `merkle_proofs`. `CommitmentWriter`
+// and `Prover` do not salt leaves, so opened leaves are hashed and proven unsalted.
 #[derive(Clone, CanonicalSerialize, CanonicalDeserialize)]
 pub struct StirProof<F, MerkleConfig>
 where
@@ -16,6 +23,7 @@ where
     MerkleConfig: Config<Leaf = [F]>,
 {
     merkle_proofs: Vec<(MultiPath<MerkleConfig>, Vec<Vec<F>>)>,
+    leaf_salts: Vec<Vec<Vec<F>>>,
 }
 
 pub fn stir_proof_size<MerkleConfig, F>(
@@ -48,8 +56,12 @@ mod tests {
             FoldType, FoldingFactor, ProtocolParameters, SoundnessType, UnivariateParameters,
         },
         stir_ldt::{
-            committer::CommitmentWriter, domainsep::StirDomainSeparator, parameters::StirConfig,
-            prover::Prover, verifier::Verifier,
+            committer::CommitmentWriter,
+            domainsep::StirDomainSeparator,
+            hiding::{commit_hiding, within_query_budget},
+            parameters::StirConfig,
+            prover::Prover,
+            verifier::Verifier,
         },
     };
 
@@ -63,6 +75,7 @@ mod tests {
         fold_type: FoldType,
         soundness_type: SoundnessType,
         pow_bits: usize,
+        hiding: bool,
     ) {
         dbg!((
             folding_factor,
@@ -70,6 +83,7 @@ mod tests {
             fold_type,
             soundness_type,
             pow_bits,
+            hiding,
         ));
 
         let num_coeffs = 1 << log_degree;
@@ -90,6 +104,7 @@ mod tests {
             fold_optimisation: fold_type,
             soundness_type,
             starting_log_inv_rate: 1,
+            hiding,
             _pow_parameters: Default::default(),
         };
 
@@ -97,15 +112,30 @@ mod tests {
 
         let polynomial = DensePolynomial::from_coefficients_vec(vec![F::from(1); num_coeffs]);
 
-        let domainsep = DomainSeparator::<DefaultHash>::new("🌪️")
+        let mut domainsep = DomainSeparator::<DefaultHash>::new("🌪️")
             .commit_statement(&params)
-            .add_stir_proof(&params)
-            .clone();
+            .add_stir_proof(&params);
+        if hiding {
+            // `commit_hiding` absorbs one extra commitment (to the unblinded polynomial) before
+            // drawing the masking challenge, so it needs transcript space beyond the plain path.
+            domainsep = domainsep.add_stir_proof(&params);
+        }
+        let domainsep = domainsep.clone();
 
         let mut prover_state = domainsep.to_prover_state();
 
-        let committer = CommitmentWriter::new(params.clone());
-        let witness = committer.commit(&mut prover_state, polynomial).unwrap();
+        // `commit_hiding` refuses to run once the protocol's query count would exceed the
+        // masking polynomial's randomness budget (see `within_query_budget`); tiny degrees in
+        // this sweep can legitimately hit that, so fall back to the plain commit path rather
+        // than asserting on a parameter combination hiding was never going to be safe for.
+        let witness = if hiding && within_query_budget(params.num_queries(), num_coeffs) {
+            commit_hiding(&params, &mut prover_state, polynomial, &mut rng)
+                .unwrap()
+                .blinded_witness
+        } else {
+            let committer = CommitmentWriter::new(params.clone());
+            committer.commit(&mut prover_state, polynomial).unwrap()
+        };
 
         let prover = Prover::new(params.clone());
 
@@ -127,17 +157,21 @@ mod tests {
         ];
         let fold_types = [FoldType::Naive, FoldType::ProverHelps];
         let pow_bitss = [0, 5, 10];
+        let hidings = [false, true];
         for folding_factor in folding_factors {
             for soundness_type in soundness_types {
                 for fold_type in fold_types {
                     for pow_bits in pow_bitss {
-                        make_stir_things(
-                            folding_factor,
-                            log_degree,
-                            fold_type,
-                            soundness_type,
-                            pow_bits,
-                        );
+                        for hiding in hidings {
+                            make_stir_things(
+                                folding_factor,
+                                log_degree,
+                                fold_type,
+                                soundness_type,
+                                pow_bits,
+                                hiding,
+                            );
+                        }
                     }
                 }
             }
@@ -157,6 +191,7 @@ mod tests {
             SoundnessType::UniqueDecoding,
         ];
         let pow_bits = [0, 5, 10];
+        let hidings = [false, true];
 
         for folding_factor in folding_factors {
             let num_variables = folding_factor..=3 * folding_factor;
@@ -164,13 +199,16 @@ mod tests {
                 for fold_type in fold_types {
                     for soundness_type in soundness_type {
                         for pow_bits in pow_bits {
-                            make_stir_things(
-                                folding_factor,
-                                num_variables,
-                                fold_type,
-                                soundness_type,
-                                pow_bits,
-                            );
+                            for hiding in hidings {
+                                make_stir_things(
+                                    folding_factor,
+                                    num_variables,
+                                    fold_type,
+                                    soundness_type,
+                                    pow_bits,
+                                    hiding,
+                                );
+                            }
                         }
                     }
                 }
@@ -0,0 +1,339 @@
+//! Streaming commit/prove path for instances too large to materialize as a full evaluation
+//! table. Trades extra passes over the data for resident memory that no longer scales with the
+//! full codeword: leaves are hashed incrementally as chunks arrive, and queries are answered by
+//! re-streaming a codeword instead of keeping it resident.
+//!
+//! # Known limitations
+//!
+//! - [`StreamingCommitmentWriter::commit_streaming`] absorbs its root into the transcript like
+//!   `CommitmentWriter::commit` does, but returns the bare root rather than a `Witness`: building
+//!   a real `Witness` is `committer.rs`'s job and that module has no constructor this series can
+//!   call, so the streamed root is not yet something `Prover::prove` can consume directly.
+//! - [`StreamingProver::answer_queries`] returns bare field values, not `MultiPath`
+//!   authentication paths, so its output cannot populate `StirProof::merkle_proofs` either.
+//!   Producing real paths from a bounded-memory pass needs the same sibling-hash bookkeeping
+//!   `MultiPath` itself uses internally; wiring that up is future work for this module.
+
+use ark_crypto_primitives::{
+    crh::{CRHScheme, TwoToOneCRHScheme},
+    merkle_tree::Config,
+};
+use ark_ff::Field;
+use ark_serialize::CanonicalSerialize;
+use spongefish::ProverState;
+
+use crate::{poly_utils::streaming_evaluation_helper::EvaluationStream, stir_ldt::parameters::StirConfig};
+
+/// Sets the chunk size, in field elements, in which [`StreamingCommitmentWriter`] and
+/// [`StreamingProver`] read their evaluation stream. A budget below one leaf
+/// (`1 << folding_factor_at_round(0)` elements) is raised to one leaf; the stream is read in a
+/// single pass whatever the budget.
+#[derive(Clone, Copy, Debug)]
+pub struct MemoryBudget {
+    pub resident_field_elements: usize,
+}
+
+impl MemoryBudget {
+    /// A budget of `O(sqrt(n))` resident field elements for an evaluation table of `n`
+    /// elements, the classical space/time tradeoff point for streaming commitments.
+    pub fn sqrt_n(n: usize) -> Self {
+        Self {
+            resident_field_elements: (n as f64).sqrt().ceil() as usize,
+        }
+    }
+
+    /// A budget of `O(log n)` resident field elements for an evaluation table of `n` elements.
+    pub fn log_n(n: usize) -> Self {
+        Self {
+            resident_field_elements: (usize::BITS - n.max(1).leading_zeros()) as usize,
+        }
+    }
+}
+
+/// Streaming counterpart of `CommitmentWriter`: consumes evaluation chunks one at a time and
+/// hashes each leaf as soon as it is complete, so the full evaluation table is never resident
+/// at once. Every leaf digest is kept until the stream ends and is then folded up to the root,
+/// so one digest per leaf stays resident, not only a Merkle frontier. Exposes the same commitment output as the
+/// in-memory committer once the stream is exhausted.
+///
+/// This assumes `MerkleConfig::LeafDigest` and `MerkleConfig::InnerDigest` coincide (true of
+/// the Blake3 tree this crate's tests use), so a leaf hash can be folded directly with the
+/// two-to-one hash without going through a separate leaf-to-inner digest conversion.
+pub struct StreamingCommitmentWriter<F, MerkleConfig, PowStrategy>
+where
+    F: Field,
+    MerkleConfig: Config<Leaf = [F], LeafDigest = <MerkleConfig as Config>::InnerDigest>,
+{
+    params: StirConfig<F, MerkleConfig, PowStrategy>,
+    memory_budget: MemoryBudget,
+}
+
+impl<F, MerkleConfig, PowStrategy> StreamingCommitmentWriter<F, MerkleConfig, PowStrategy>
+where
+    F: Field,
+    MerkleConfig: Config<Leaf = [F], LeafDigest = <MerkleConfig as Config>::InnerDigest>,
+{
+    pub fn new(params: StirConfig<F, MerkleConfig, PowStrategy>, memory_budget: MemoryBudget) -> Self {
+        Self {
+            params,
+            memory_budget,
+        }
+    }
+
+    /// Commits to a polynomial given only as a stream of evaluation chunks, holding at most one
+    /// read chunk of `max(self.memory_budget, leaf_len)` evaluations plus fewer than `leaf_len`
+    /// left over from the previous chunk at a time. `evals` is read in those pieces, but they are re-buffered into exactly
+    /// `1 << folding_factor_at_round(0)`-sized leaves before hashing — the memory budget governs
+    /// I/O granularity only, not the tree's actual leaf size, so the resulting tree is the same
+    /// shape `CommitmentWriter::commit` would build from the same evaluations in memory. The
+    /// root is absorbed into `prover_state` exactly as `CommitmentWriter::commit` absorbs its
+    /// own root, so a streamed commitment binds into the transcript the same way.
+    ///
+    /// Returns the bare tree root rather than a `Witness`: see this module's "Known limitations"
+    /// for why that isn't yet something `Prover::prove` can consume.
+    pub fn commit_streaming<S: EvaluationStream<F>>(
+        &self,
+        prover_state: &mut ProverState,
+        evals: S,
+    ) -> crate::Result<MerkleConfig::InnerDigest> {
+        let leaf_len = 1 << self.params.folding_factor_at_round(0);
+        let read_chunk_len = self.memory_budget.resident_field_elements.max(leaf_len);
+
+        let leaf_hash_params = self.params.leaf_hash_params();
+        let mut completed_leaves = Vec::new();
+        let mut buffer = Vec::with_capacity(leaf_len);
+        for chunk in evals.chunks(read_chunk_len) {
+            buffer.extend(chunk);
+            while buffer.len() >= leaf_len {
+                let leaf: Vec<F> = buffer.drain(..leaf_len).collect();
+                completed_leaves.push(hash_leaf::<F, MerkleConfig>(leaf_hash_params, &leaf)?);
+            }
+        }
+        if !buffer.is_empty() {
+            completed_leaves.push(hash_leaf::<F, MerkleConfig>(leaf_hash_params, &buffer)?);
+        }
+
+        let two_to_one_params = self.params.two_to_one_params();
+        let root = fold_frontier::<F, MerkleConfig>(two_to_one_params, completed_leaves)?;
+
+        let mut root_bytes = Vec::new();
+        root.serialize_compressed(&mut root_bytes)
+            .expect("serializing a Merkle root to an in-memory buffer cannot fail");
+        prover_state.public_bytes(&root_bytes)?;
+
+        Ok(root)
+    }
+}
+
+fn hash_leaf<F: Field, MerkleConfig>(
+    leaf_hash_params: &<MerkleConfig::LeafHash as CRHScheme>::Parameters,
+    leaf: &[F],
+) -> crate::Result<MerkleConfig::InnerDigest>
+where
+    MerkleConfig: Config<Leaf = [F], LeafDigest = <MerkleConfig as Config>::InnerDigest>,
+{
+    MerkleConfig::LeafHash::evaluate(leaf_hash_params, leaf.to_vec()).map_err(Into::into)
+}
+
+/// Folds a layer of leaf-hash digests pairwise up to a single root via the two-to-one hash,
+/// never holding more than one layer at a time.
+fn fold_frontier<F: Field, MerkleConfig>(
+    two_to_one_params: &<MerkleConfig::TwoToOneHash as TwoToOneCRHScheme>::Parameters,
+    mut level: Vec<MerkleConfig::InnerDigest>,
+) -> crate::Result<MerkleConfig::InnerDigest>
+where
+    MerkleConfig: Config<Leaf = [F]>,
+{
+    assert!(!level.is_empty(), "cannot fold an empty evaluation stream");
+    while level.len() > 1 {
+        let mut next = Vec::with_capacity(level.len().div_ceil(2));
+        for pair in level.chunks(2) {
+            let combined = if let [left, right] = pair {
+                MerkleConfig::TwoToOneHash::evaluate(two_to_one_params, left.clone(), right.clone())
+                    .map_err(Into::into)?
+            } else {
+                pair[0].clone()
+            };
+            next.push(combined);
+        }
+        level = next;
+    }
+    Ok(level.into_iter().next().unwrap())
+}
+
+/// Streaming prover: answers queries by streaming a codeword once rather than keeping it
+/// resident. Each [`Self::answer_queries`] call reads one stream supplied by the caller; the
+/// prover does not fold codewords between rounds and holds no folding randomness.
+pub struct StreamingProver<F, MerkleConfig, PowStrategy>
+where
+    F: Field,
+    MerkleConfig: Config<Leaf = [F], LeafDigest = <MerkleConfig as Config>::InnerDigest>,
+{
+    params: StirConfig<F, MerkleConfig, PowStrategy>,
+    memory_budget: MemoryBudget,
+}
+
+impl<F, MerkleConfig, PowStrategy> StreamingProver<F, MerkleConfig, PowStrategy>
+where
+    F: Field,
+    MerkleConfig: Config<Leaf = [F], LeafDigest = <MerkleConfig as Config>::InnerDigest>,
+{
+    pub fn new(params: StirConfig<F, MerkleConfig, PowStrategy>, memory_budget: MemoryBudget) -> Self {
+        Self {
+            params,
+            memory_budget,
+        }
+    }
+
+    /// Re-streams `evals` once to read off the values at `query_indices`, rather than holding
+    /// the whole codeword resident to answer them. Indices are assumed sorted, matching how the
+    /// native (in-memory) prover answers a round's queries in index order.
+    ///
+    /// Returns bare values, not `MultiPath` authentication paths — see this module's "Known
+    /// limitations" for why the result can't populate `StirProof::merkle_proofs` yet.
+    pub fn answer_queries<S: EvaluationStream<F>>(
+        &self,
+        evals: S,
+        query_indices: &[usize],
+    ) -> Vec<F> {
+        let read_chunk_len = self
+            .memory_budget
+            .resident_field_elements
+            .max(1 << self.params.folding_factor_at_round(0));
+
+        let mut answers = Vec::with_capacity(query_indices.len());
+        let mut next_query = 0;
+        let mut offset = 0;
+        for chunk in evals.chunks(read_chunk_len) {
+            while next_query < query_indices.len() {
+                let index = query_indices[next_query];
+                if index >= offset + chunk.len() {
+                    break;
+                }
+                answers.push(chunk[index - offset]);
+                next_query += 1;
+            }
+            offset += chunk.len();
+        }
+        answers
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use spongefish::{DefaultHash, DomainSeparator};
+    use spongefish_pow::blake3::Blake3PoW;
+
+    use super::*;
+    use crate::{
+        crypto::{
+            fields::Field64,
+            merkle_tree::{
+                blake3::{Blake3Compress, Blake3LeafHash, Blake3MerkleTreeParams},
+                parameters::default_config,
+            },
+        },
+        parameters::{FoldType, FoldingFactor, ProtocolParameters, SoundnessType, UnivariateParameters},
+        stir_ldt::domainsep::StirDomainSeparator,
+    };
+
+    type MerkleConfig = Blake3MerkleTreeParams<F>;
+    type PowStrategy = Blake3PoW;
+    type F = Field64;
+
+    struct VecStream(Vec<F>);
+
+    struct VecChunks {
+        data: Vec<F>,
+        chunk_len: usize,
+        pos: usize,
+    }
+
+    impl Iterator for VecChunks {
+        type Item = Vec<F>;
+
+        fn next(&mut self) -> Option<Self::Item> {
+            if self.pos >= self.data.len() {
+                return None;
+            }
+            let end = (self.pos + self.chunk_len).min(self.data.len());
+            let chunk = self.data[self.pos..end].to_vec();
+            self.pos = end;
+            Some(chunk)
+        }
+    }
+
+    impl EvaluationStream<F> for VecStream {
+        type Chunks = VecChunks;
+
+        fn chunks(self, chunk_len: usize) -> Self::Chunks {
+            VecChunks {
+                data: self.0,
+                chunk_len,
+                pos: 0,
+            }
+        }
+    }
+
+    fn test_params() -> StirConfig<F, MerkleConfig, PowStrategy> {
+        let mut rng = ark_std::test_rng();
+        let (leaf_hash_params, two_to_one_params) =
+            default_config::<F, Blake3LeafHash<F>, Blake3Compress>(&mut rng);
+        let mv_params = UnivariateParameters::<F>::new(8);
+        let stir_params = ProtocolParameters::<MerkleConfig, PowStrategy> {
+            initial_statement: false,
+            security_level: 32,
+            pow_bits: 5,
+            folding_factor: FoldingFactor::Constant(4),
+            leaf_hash_params,
+            two_to_one_params,
+            fold_optimisation: FoldType::ProverHelps,
+            soundness_type: SoundnessType::ConjectureList,
+            starting_log_inv_rate: 1,
+            hiding: false,
+            _pow_parameters: Default::default(),
+        };
+        StirConfig::<F, MerkleConfig, PowStrategy>::new(mv_params, stir_params)
+    }
+
+    #[test]
+    fn test_answer_queries_reads_off_values_across_chunk_boundaries() {
+        let prover = StreamingProver::new(test_params(), MemoryBudget { resident_field_elements: 3 });
+        // The budget of 3 is raised to the 16-element leaf size, so 40 values span three chunks.
+        let data: Vec<F> = (0..40u64).map(F::from).collect();
+        let query_indices = [0usize, 4, 20, 39];
+
+        let answers = prover.answer_queries(VecStream(data.clone()), &query_indices);
+
+        assert_eq!(
+            answers,
+            query_indices.iter().map(|&i| data[i]).collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn test_commit_streaming_root_is_independent_of_the_memory_budget() {
+        let params = test_params();
+        let data: Vec<F> = (0..64u64).map(F::from).collect();
+
+        // Neither budget is a multiple of the other, and neither lines up with the tree's real
+        // leaf size (`1 << folding_factor_at_round(0) == 16`); the memory budget only bounds how
+        // many evaluations are read into memory at a time, so both must still fold the same
+        // leaves and land on the same root.
+        let domainsep = DomainSeparator::<DefaultHash>::new("streaming-test").commit_statement(&params);
+
+        let small_budget = StreamingCommitmentWriter::new(params.clone(), MemoryBudget { resident_field_elements: 3 });
+        let mut prover_state = domainsep.to_prover_state();
+        let root_from_small_budget = small_budget
+            .commit_streaming(&mut prover_state, VecStream(data.clone()))
+            .unwrap();
+
+        let large_budget = StreamingCommitmentWriter::new(params, MemoryBudget { resident_field_elements: 37 });
+        let mut prover_state = domainsep.to_prover_state();
+        let root_from_large_budget = large_budget
+            .commit_streaming(&mut prover_state, VecStream(data))
+            .unwrap();
+
+        assert_eq!(root_from_small_budget, root_from_large_budget);
+    }
+}